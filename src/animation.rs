@@ -0,0 +1,155 @@
+use crate::player::{Player, PlayerState};
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::time::Duration;
+
+const IDLE_SPRITE_INDICES: (usize, usize) = (0, 9);
+const IDLE_SPRITE_TIMER: f32 = 0.1;
+
+const RUN_SPRITE_INDICES: (usize, usize) = (10, 19);
+const RUN_SPRITE_TIMER: f32 = 0.05;
+
+// No dedicated jump/fall art yet: hold the first idle frame until the sprite
+// sheet grows a jump/fall row.
+const AIRBORNE_SPRITE_INDEX: usize = IDLE_SPRITE_INDICES.0;
+const AIRBORNE_SPRITE_TIMER: f32 = 1.0;
+
+/// Describes one state's animation: the frame range to play, how fast to
+/// advance through it, and whether to loop or hold the last frame.
+#[derive(Clone, Copy)]
+pub struct AnimationClip {
+    pub first: usize,
+    pub last: usize,
+    pub seconds_per_frame: f32,
+    pub looping: bool,
+}
+
+/// Maps each `PlayerState` to the clip it should play. Adding a new state's
+/// animation is just a new entry here, not a new system.
+#[derive(Resource, Deref)]
+pub struct AnimationClips(HashMap<PlayerState, AnimationClip>);
+
+#[derive(Component)]
+pub struct ActiveAnimation(AnimationClip);
+
+impl ActiveAnimation {
+    pub fn new(clip: AnimationClip) -> Self {
+        Self(clip)
+    }
+}
+
+#[derive(Component, Deref, DerefMut)]
+pub struct AnimationTimer(pub Timer);
+
+impl AnimationTimer {
+    pub fn new(clip: AnimationClip) -> Self {
+        Self(Timer::from_seconds(
+            clip.seconds_per_frame,
+            TimerMode::Repeating,
+        ))
+    }
+}
+
+pub struct AnimationPlugin;
+
+impl Plugin for AnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(player_animation_clips()).add_systems(
+            Update,
+            (
+                update_active_clip.run_if(state_changed::<PlayerState>),
+                animate_player,
+            )
+                .chain(),
+        );
+    }
+}
+
+fn player_animation_clips() -> AnimationClips {
+    let mut clips = HashMap::new();
+    clips.insert(
+        PlayerState::Idle,
+        AnimationClip {
+            first: IDLE_SPRITE_INDICES.0,
+            last: IDLE_SPRITE_INDICES.1,
+            seconds_per_frame: IDLE_SPRITE_TIMER,
+            looping: true,
+        },
+    );
+    clips.insert(
+        PlayerState::Run,
+        AnimationClip {
+            first: RUN_SPRITE_INDICES.0,
+            last: RUN_SPRITE_INDICES.1,
+            seconds_per_frame: RUN_SPRITE_TIMER,
+            looping: true,
+        },
+    );
+    clips.insert(
+        PlayerState::Jump,
+        AnimationClip {
+            first: AIRBORNE_SPRITE_INDEX,
+            last: AIRBORNE_SPRITE_INDEX,
+            seconds_per_frame: AIRBORNE_SPRITE_TIMER,
+            looping: false,
+        },
+    );
+    clips.insert(
+        PlayerState::Fall,
+        AnimationClip {
+            first: AIRBORNE_SPRITE_INDEX,
+            last: AIRBORNE_SPRITE_INDEX,
+            seconds_per_frame: AIRBORNE_SPRITE_TIMER,
+            looping: false,
+        },
+    );
+
+    AnimationClips(clips)
+}
+
+pub fn default_clip(clips: &AnimationClips, player_state: PlayerState) -> AnimationClip {
+    *clips
+        .get(&player_state)
+        .expect("every PlayerState should have an AnimationClip entry")
+}
+
+fn update_active_clip(
+    player_state: Res<State<PlayerState>>,
+    clips: Res<AnimationClips>,
+    mut query: Query<(&mut ActiveAnimation, &mut AnimationTimer, &mut Sprite), With<Player>>,
+) {
+    let Some(clip) = clips.get(player_state.get()) else {
+        return;
+    };
+
+    if let Ok((mut active, mut timer, mut sprite)) = query.single_mut() {
+        active.0 = *clip;
+        timer.set_duration(Duration::from_secs_f32(clip.seconds_per_frame));
+        timer.reset();
+
+        if let Some(atlas) = sprite.texture_atlas.as_mut() {
+            atlas.index = clip.first;
+        }
+    }
+}
+
+fn animate_player(
+    time: Res<Time>,
+    mut query: Query<(&ActiveAnimation, &mut AnimationTimer, &mut Sprite), With<Player>>,
+) {
+    if let Ok((active, mut timer, mut sprite)) = query.single_mut() {
+        timer.tick(time.delta());
+
+        if timer.just_finished() {
+            if let Some(atlas) = sprite.texture_atlas.as_mut() {
+                if atlas.index == active.0.last {
+                    if active.0.looping {
+                        atlas.index = active.0.first;
+                    }
+                } else {
+                    atlas.index += 1;
+                }
+            }
+        }
+    }
+}
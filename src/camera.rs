@@ -0,0 +1,54 @@
+use crate::player::Player;
+use bevy::audio::SpatialListener;
+use bevy::prelude::*;
+
+const CAMERA_FOLLOW_RATE: f32 = 10.0;
+const CAMERA_DEAD_ZONE: f32 = 8.0;
+const CAMERA_PROJECTION_SCALE: f32 = 1.0;
+
+/// Marks the camera that eases toward the player (currently just the one camera).
+#[derive(Component)]
+pub struct CameraTarget;
+
+pub struct CameraPlugin;
+
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_camera)
+            .add_systems(PostUpdate, follow_player);
+    }
+}
+
+fn spawn_camera(mut commands: Commands) {
+    commands.spawn((
+        Camera2d,
+        Projection::Orthographic(OrthographicProjection {
+            scale: CAMERA_PROJECTION_SCALE,
+            ..OrthographicProjection::default_2d()
+        }),
+        CameraTarget,
+        SpatialListener::new(20.0),
+    ));
+}
+
+fn follow_player(
+    player_query: Query<&Transform, (With<Player>, Without<CameraTarget>)>,
+    mut camera_query: Query<&mut Transform, With<CameraTarget>>,
+    time: Res<Time>,
+) {
+    let (Ok(player_transform), Ok(mut camera_transform)) =
+        (player_query.single(), camera_query.single_mut())
+    else {
+        return;
+    };
+
+    let target = player_transform.translation;
+    let offset = target - camera_transform.translation;
+
+    if offset.truncate().length() < CAMERA_DEAD_ZONE {
+        return;
+    }
+
+    let smoothing = 1.0 - (-CAMERA_FOLLOW_RATE * time.delta_secs()).exp();
+    camera_transform.translation = camera_transform.translation.lerp(target, smoothing);
+}
@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+const LEVEL_WIDTH: f32 = 1000.0;
+const LEVEL_HEIGHT: f32 = 600.0;
+const WALL_THICKNESS: f32 = 50.0;
+
+const WALL_COLOR: Color = Color::srgb(0.2, 0.2, 0.25);
+
+pub struct LevelPlugin;
+
+impl Plugin for LevelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_arena);
+    }
+}
+
+fn spawn_arena(mut commands: Commands) {
+    let half_width = LEVEL_WIDTH / 2.0;
+    let half_height = LEVEL_HEIGHT / 2.0;
+    let half_thickness = WALL_THICKNESS / 2.0;
+
+    let walls = [
+        // Floor
+        (
+            Vec2::new(0.0, -half_height - half_thickness),
+            Vec2::new(half_width + WALL_THICKNESS, half_thickness),
+        ),
+        // Ceiling
+        (
+            Vec2::new(0.0, half_height + half_thickness),
+            Vec2::new(half_width + WALL_THICKNESS, half_thickness),
+        ),
+        // Left wall
+        (
+            Vec2::new(-half_width - half_thickness, 0.0),
+            Vec2::new(half_thickness, half_height + WALL_THICKNESS),
+        ),
+        // Right wall
+        (
+            Vec2::new(half_width + half_thickness, 0.0),
+            Vec2::new(half_thickness, half_height + WALL_THICKNESS),
+        ),
+    ];
+
+    for (position, half_extents) in walls {
+        let mut wall = commands.spawn((
+            RigidBody::Fixed,
+            Collider::cuboid(half_extents.x, half_extents.y),
+            Transform::from_translation(position.extend(0.0)),
+        ));
+
+        if cfg!(debug_assertions) {
+            wall.insert(Sprite {
+                color: WALL_COLOR,
+                custom_size: Some(half_extents * 2.0),
+                ..default()
+            });
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+
+const PLAYER_SPRITE_GRID: (UVec2, u32, u32, Option<UVec2>, Option<UVec2>) =
+    (UVec2::new(110, 80), 10, 2, Some(UVec2::new(10, 0)), None);
+
+/// Top-level app flow: block on asset loading before letting gameplay spawn.
+#[derive(States, Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub enum AppState {
+    #[default]
+    Loading,
+    InGame,
+}
+
+/// Image handles loaded at startup. Grows alongside `Layouts` (and later
+/// `Sounds`/`Fonts`) as the asset set grows, instead of inline
+/// `asset_server.load` calls scattered across spawn systems.
+#[derive(Default)]
+pub struct Images {
+    pub player_sprite_sheet: Handle<Image>,
+}
+
+#[derive(Default)]
+pub struct Layouts {
+    pub player_sprite_sheet: Handle<TextureAtlasLayout>,
+}
+
+#[derive(Resource, Default)]
+pub struct AssetLoader {
+    pub images: Images,
+    pub layouts: Layouts,
+}
+
+pub struct LoadingPlugin;
+
+impl Plugin for LoadingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<AppState>()
+            .add_systems(Startup, load_assets)
+            .add_systems(OnEnter(AppState::Loading), spawn_loading_ui)
+            .add_systems(OnExit(AppState::Loading), despawn_loading_ui)
+            .add_systems(Update, check_loaded.run_if(in_state(AppState::Loading)));
+    }
+}
+
+fn load_assets(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+) {
+    let player_sprite_sheet = asset_server.load("player_sprite_sheet.png");
+    let player_sprite_sheet_layout = texture_atlas_layouts.add(TextureAtlasLayout::from_grid(
+        PLAYER_SPRITE_GRID.0,
+        PLAYER_SPRITE_GRID.1,
+        PLAYER_SPRITE_GRID.2,
+        PLAYER_SPRITE_GRID.3,
+        PLAYER_SPRITE_GRID.4,
+    ));
+
+    commands.insert_resource(AssetLoader {
+        images: Images {
+            player_sprite_sheet,
+        },
+        layouts: Layouts {
+            player_sprite_sheet: player_sprite_sheet_layout,
+        },
+    });
+}
+
+fn check_loaded(
+    asset_server: Res<AssetServer>,
+    asset_loader: Res<AssetLoader>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    let loaded = matches!(
+        asset_server.get_load_state(&asset_loader.images.player_sprite_sheet),
+        Some(LoadState::Loaded)
+    );
+
+    if loaded {
+        next_app_state.set(AppState::InGame);
+    }
+}
+
+#[derive(Component)]
+struct LoadingText;
+
+fn spawn_loading_ui(mut commands: Commands) {
+    commands.spawn((
+        Text::new("Loading..."),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(16.0),
+            left: Val::Px(16.0),
+            ..default()
+        },
+        LoadingText,
+    ));
+}
+
+fn despawn_loading_ui(mut commands: Commands, query: Query<Entity, With<LoadingText>>) {
+    for entity in &query {
+        commands.entity(entity).despawn();
+    }
+}
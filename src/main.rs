@@ -1,22 +1,29 @@
+mod animation;
+mod camera;
+mod level;
+mod loading;
 mod player;
+mod sound;
 
+use animation::AnimationPlugin;
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
+use camera::CameraPlugin;
+use level::LevelPlugin;
+use loading::LoadingPlugin;
 use player::PlayerPlugin;
+use sound::SoundPlugin;
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins.set(ImagePlugin::default_nearest()))
         .add_plugins(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(100.0))
         .add_plugins(RapierDebugRenderPlugin::default())
+        .add_plugins(LoadingPlugin)
+        .add_plugins(CameraPlugin)
+        .add_plugins(AnimationPlugin)
+        .add_plugins(SoundPlugin)
         .add_plugins(PlayerPlugin)
-        .add_systems(Startup, create_ground)
+        .add_plugins(LevelPlugin)
         .run();
 }
-
-fn create_ground(mut commands: Commands) {
-    commands.spawn((
-        Collider::cuboid(500.0, 50.0),
-        Transform::from_xyz(0.0, -250.0, 0.0),
-    ));
-}
@@ -0,0 +1,103 @@
+use crate::player::{Player, PlayerState};
+use bevy::audio::{PlaybackMode, SpatialScale};
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::KinematicCharacterControllerOutput;
+
+/// Handles to the effects loaded at startup, grouped the same way
+/// `AssetLoader` groups images elsewhere in the app.
+#[derive(Resource)]
+pub struct Sounds {
+    footstep: Handle<AudioSource>,
+    jump: Handle<AudioSource>,
+    land: Handle<AudioSource>,
+}
+
+/// Marks the looping footstep sink so it can be stopped when the player
+/// leaves `Run`.
+#[derive(Component)]
+struct Footstep;
+
+pub struct SoundPlugin;
+
+impl Plugin for SoundPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, load_sounds)
+            .add_systems(OnEnter(PlayerState::Run), start_footsteps)
+            .add_systems(OnExit(PlayerState::Run), stop_footsteps)
+            .add_systems(OnEnter(PlayerState::Jump), play_jump)
+            .add_systems(Update, play_landing);
+    }
+}
+
+fn load_sounds(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(Sounds {
+        footstep: asset_server.load("sounds/footstep.ogg"),
+        jump: asset_server.load("sounds/jump.ogg"),
+        land: asset_server.load("sounds/land.ogg"),
+    });
+}
+
+fn spatial_playback(mode: PlaybackMode) -> PlaybackSettings {
+    PlaybackSettings {
+        mode,
+        spatial: true,
+        spatial_scale: Some(SpatialScale::new(1.0 / 100.0)),
+        ..default()
+    }
+}
+
+fn start_footsteps(
+    mut commands: Commands,
+    sounds: Res<Sounds>,
+    player_query: Query<Entity, With<Player>>,
+) {
+    if let Ok(player) = player_query.single() {
+        commands.entity(player).with_child((
+            AudioPlayer(sounds.footstep.clone()),
+            spatial_playback(PlaybackMode::Loop),
+            Transform::IDENTITY,
+            Footstep,
+        ));
+    }
+}
+
+fn stop_footsteps(mut commands: Commands, query: Query<Entity, With<Footstep>>) {
+    for entity in &query {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn play_jump(
+    mut commands: Commands,
+    sounds: Res<Sounds>,
+    player_query: Query<Entity, With<Player>>,
+) {
+    if let Ok(player) = player_query.single() {
+        commands.entity(player).with_child((
+            AudioPlayer(sounds.jump.clone()),
+            spatial_playback(PlaybackMode::Despawn),
+            Transform::IDENTITY,
+        ));
+    }
+}
+
+fn play_landing(
+    mut commands: Commands,
+    sounds: Res<Sounds>,
+    player_query: Query<(Entity, &KinematicCharacterControllerOutput), With<Player>>,
+    mut was_grounded: Local<bool>,
+) {
+    let Ok((player, output)) = player_query.single() else {
+        return;
+    };
+
+    if output.grounded && !*was_grounded {
+        commands.entity(player).with_child((
+            AudioPlayer(sounds.land.clone()),
+            spatial_playback(PlaybackMode::Despawn),
+            Transform::IDENTITY,
+        ));
+    }
+
+    *was_grounded = output.grounded;
+}